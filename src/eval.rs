@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+
+use super::mat::{RNMat, RNMatError};
+use super::rnum::RNum;
+
+type Result<T> = std::result::Result<T, RNMatError>;
+
+/// Evaluates an expression over matrix/scalar literals: `+`, `-`, `*`
+/// (matrix-matrix or matrix-scalar), unary `-`, postfix `'` (transpose),
+/// and parenthesization. Matrix literals use the same grammar as
+/// `RNMat::parse`, e.g. `[1/2 3; 4 -5/6] * 2`. Takes no variables; use
+/// [`eval_with_vars`] to resolve identifiers such as `A` against a bound
+/// matrix.
+///
+/// # Examples
+///
+/// ```
+/// use rnmat::eval::eval;
+/// let m = eval("[1 2; 3 4] + [1 0; 0 1]").unwrap();
+/// ```
+pub fn eval(s: &str) -> Result<RNMat<RNum>> {
+    eval_with_vars(s, &HashMap::new())
+}
+
+/// Evaluates an expression like [`eval`], additionally resolving bare
+/// identifiers (e.g. `A`) against `vars`. An identifier not present in
+/// `vars` is a [`RNMatError::ParseError`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rnmat::eval::eval_with_vars;
+/// use rnmat::mat::RNMat;
+///
+/// let mut vars = HashMap::new();
+/// vars.insert("A".to_string(), RNMat::parse("[1 2; 3 4]").unwrap());
+/// let m = eval_with_vars("[1/2 3; 4 -5/6] * 2 + A'", &vars).unwrap();
+/// ```
+pub fn eval_with_vars(s: &str, vars: &HashMap<String, RNMat<RNum>>) -> Result<RNMat<RNum>> {
+    let tokens = tokenize(s)?;
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let expr = parser.parse_expr()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(RNMatError::ParseError);
+    }
+    eval_expr(&expr, vars)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Scalar(RNum),
+    Matrix(String),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Quote,
+    LParen,
+    RParen,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '\'' => {
+                tokens.push(Token::Quote);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '[' => {
+                let start = i;
+                let mut depth = 0;
+                while i < chars.len() {
+                    if chars[i] == '[' {
+                        depth += 1;
+                    } else if chars[i] == ']' {
+                        depth -= 1;
+                        if depth == 0 {
+                            i += 1;
+                            break;
+                        }
+                    }
+                    i += 1;
+                }
+                if depth != 0 {
+                    return Err(RNMatError::ParseError);
+                }
+                tokens.push(Token::Matrix(chars[start..i].iter().collect()));
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let n: i32 = chars[start..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse()
+                    .map_err(|_| RNMatError::ParseError)?;
+                if i < chars.len() && chars[i] == '/' {
+                    i += 1;
+                    let dstart = i;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    if dstart == i {
+                        return Err(RNMatError::ParseError);
+                    }
+                    let d: i32 = chars[dstart..i]
+                        .iter()
+                        .collect::<String>()
+                        .parse()
+                        .map_err(|_| RNMatError::ParseError)?;
+                    tokens.push(Token::Scalar(RNum::safe_make(n, d).ok_or(RNMatError::ParseError)?));
+                } else {
+                    tokens.push(Token::Scalar(RNum::new(n, 1)));
+                }
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            _ => return Err(RNMatError::ParseError),
+        }
+    }
+    Ok(tokens)
+}
+
+/// AST node. `Literal` covers both matrix literals and scalars, the latter
+/// represented as a `1x1` matrix.
+#[derive(Debug)]
+enum Expr {
+    Literal(RNMat<RNum>),
+    Var(String),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Neg(Box<Expr>),
+    Transpose(Box<Expr>),
+}
+
+/// Recursive-descent parser over the grammar:
+/// ```text
+/// expr   := term (('+' | '-') term)*
+/// term   := unary ('*' unary)*
+/// unary  := '-' unary | postfix
+/// postfix:= primary '\''*
+/// primary:= Scalar | Matrix | Ident | '(' expr ')'
+/// ```
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Option<&Token> {
+        let t = self.tokens.get(self.pos);
+        self.pos += 1;
+        t
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr> {
+        let mut lhs = self.parse_term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.bump();
+                    let rhs = self.parse_term()?;
+                    lhs = Expr::Add(Box::new(lhs), Box::new(rhs));
+                }
+                Some(Token::Minus) => {
+                    self.bump();
+                    let rhs = self.parse_term()?;
+                    lhs = Expr::Sub(Box::new(lhs), Box::new(rhs));
+                }
+                _ => break,
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn parse_term(&mut self) -> Result<Expr> {
+        let mut lhs = self.parse_unary()?;
+        while let Some(Token::Star) = self.peek() {
+            self.bump();
+            let rhs = self.parse_unary()?;
+            lhs = Expr::Mul(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        if let Some(Token::Minus) = self.peek() {
+            self.bump();
+            let inner = self.parse_unary()?;
+            return Ok(Expr::Neg(Box::new(inner)));
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_primary()?;
+        while let Some(Token::Quote) = self.peek() {
+            self.bump();
+            expr = Expr::Transpose(Box::new(expr));
+        }
+        Ok(expr)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr> {
+        match self.bump().cloned() {
+            Some(Token::Scalar(n)) => {
+                let mut m = RNMat::new();
+                m.push_row(vec![n]).unwrap();
+                Ok(Expr::Literal(m))
+            }
+            Some(Token::Matrix(lit)) => Ok(Expr::Literal(RNMat::parse(&lit)?)),
+            Some(Token::Ident(name)) => Ok(Expr::Var(name)),
+            Some(Token::LParen) => {
+                let inner = self.parse_expr()?;
+                match self.bump() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(RNMatError::ParseError),
+                }
+            }
+            _ => Err(RNMatError::ParseError),
+        }
+    }
+}
+
+fn eval_expr(e: &Expr, vars: &HashMap<String, RNMat<RNum>>) -> Result<RNMat<RNum>> {
+    match e {
+        Expr::Literal(m) => Ok(m.clone()),
+        Expr::Var(name) => vars.get(name).cloned().ok_or(RNMatError::ParseError),
+        Expr::Neg(a) => Ok(-&eval_expr(a, vars)?),
+        Expr::Transpose(a) => Ok(eval_expr(a, vars)?.transpose()),
+        Expr::Add(a, b) => &eval_expr(a, vars)? + &eval_expr(b, vars)?,
+        Expr::Sub(a, b) => &eval_expr(a, vars)? - &eval_expr(b, vars)?,
+        Expr::Mul(a, b) => {
+            let lhs = eval_expr(a, vars)?;
+            let rhs = eval_expr(b, vars)?;
+            if lhs.row_num() == 1 && lhs.col_num() == 1 {
+                scale(&rhs, lhs.get(0, 0).unwrap())
+            } else if rhs.row_num() == 1 && rhs.col_num() == 1 {
+                scale(&lhs, rhs.get(0, 0).unwrap())
+            } else {
+                &lhs * &rhs
+            }
+        }
+    }
+}
+
+/// Scales every entry of `m` by `factor`, as used for matrix-scalar `*`.
+fn scale(m: &RNMat<RNum>, factor: RNum) -> Result<RNMat<RNum>> {
+    let mut out = RNMat::new();
+    for i in 0..m.row_num() {
+        let row = (0..m.col_num())
+            .map(|j| m.get(i, j).unwrap() * factor)
+            .collect();
+        out.push_row(row)?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test_eval {
+    use super::*;
+
+    #[test]
+    fn test_eval_add() {
+        assert_eq!(
+            eval("[1 2; 3 4] + [1 0; 0 1]").unwrap(),
+            RNMat::from(vec![vec![(2, 1), (2, 1)], vec![(3, 1), (5, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_eval_sub_and_unary_neg() {
+        assert_eq!(
+            eval("[1 2] - -[1 1]").unwrap(),
+            RNMat::from(vec![vec![(2, 1), (3, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_eval_matrix_mul() {
+        assert_eq!(
+            eval("[1 2; 3 4] * [1 0; 0 1]").unwrap(),
+            RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_eval_scalar_mul() {
+        assert_eq!(
+            eval("2 * [1 2; 3 4]").unwrap(),
+            RNMat::from(vec![vec![(2, 1), (4, 1)], vec![(6, 1), (8, 1)]])
+        );
+        assert_eq!(
+            eval("[1 2; 3 4] * 1/2").unwrap(),
+            RNMat::from(vec![vec![(1, 2), (1, 1)], vec![(3, 2), (2, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_eval_transpose_and_parens() {
+        assert_eq!(
+            eval("([1 2; 3 4] + [1 0; 0 1])'").unwrap(),
+            RNMat::from(vec![vec![(2, 1), (3, 1)], vec![(2, 1), (5, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_eval_dimension_mismatch() {
+        assert!(matches!(
+            eval("[1 2] + [1 2 3]"),
+            Err(RNMatError::ColDismatch)
+        ));
+    }
+
+    #[test]
+    fn test_eval_invalid_syntax() {
+        assert!(matches!(eval("[1 2] +"), Err(RNMatError::ParseError)));
+        assert!(matches!(eval("[1 2] $ [3 4]"), Err(RNMatError::ParseError)));
+    }
+
+    #[test]
+    fn test_eval_with_vars() {
+        let mut vars = HashMap::new();
+        vars.insert("A".to_string(), RNMat::parse("[1 2; 3 4]").unwrap());
+        assert_eq!(
+            eval_with_vars("[1/2 3; 4 -5/6] * 2 + A'", &vars).unwrap(),
+            eval("[1 6; 8 -5/3] + [1 3; 2 4]").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_eval_unbound_var_is_parse_error() {
+        assert!(matches!(eval("A + [1 2]"), Err(RNMatError::ParseError)));
+    }
+}
@@ -0,0 +1,16 @@
+/// Minimal field operations needed by the elimination-based algorithms in
+/// `mat`. Implemented for `RNum` (see `rnum`) and `ModInt` (see `modint`),
+/// so `RNMat<F>` can run `rref`/`determinant`/`solve`/... over either exact
+/// rationals or GF(p).
+pub trait Field: Copy {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(self, rhs: Self) -> Self;
+    fn sub(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    /// Multiplicative inverse. Implementations may panic on zero, mirroring
+    /// `RNum`'s handling of division by zero.
+    fn inv(self) -> Self;
+    fn neg(self) -> Self;
+    fn is_zero(self) -> bool;
+}
@@ -0,0 +1,6 @@
+pub mod eval;
+pub mod field;
+pub mod mat;
+pub mod modint;
+pub mod rnum;
+pub mod utils;
@@ -1,3 +1,4 @@
+use super::field::Field;
 use super::rnum::RNum;
 use std::fmt::Display;
 
@@ -6,6 +7,14 @@ pub enum RNMatError{
     RowDismatch,
     ColDismatch,
     InvalidIndex,
+    /// A full pivot set could not be found, e.g. when inverting a matrix
+    /// whose rank is less than its dimension.
+    Singular,
+    /// A literal or expression string didn't match the expected grammar.
+    ParseError,
+    /// An intermediate value overflowed even the widened arithmetic (e.g.
+    /// `i128` in `mul_reduced`).
+    Overflow,
 }
 
 impl Display for RNMatError{
@@ -18,14 +27,16 @@ impl std::error::Error for RNMatError{}
 
 type Result<T> = std::result::Result<T, RNMatError>;
 
-#[derive(Debug)]
-pub struct RNMat {
-    mat: Vec<Vec<RNum>>,
+/// A matrix over any `Field`. `RNum` as the scalar gives exact
+/// rational linear algebra; `ModInt<P>` gives linear algebra over `GF(P)`.
+#[derive(Debug, Clone)]
+pub struct RNMat<F: Field> {
+    mat: Vec<Vec<F>>,
 }
 
-impl RNMat {
+impl<F: Field> RNMat<F> {
     /// Create a empty RNMat.
-    pub fn new() -> RNMat {
+    pub fn new() -> RNMat<F> {
         RNMat { mat: Vec::new() }
     }
 
@@ -41,7 +52,7 @@ impl RNMat {
         }
     }
 
-    pub fn push_row(&mut self, row: Vec<RNum>) -> Result<()>{
+    pub fn push_row(&mut self, row: Vec<F>) -> Result<()>{
         if self.mat.len() > 0 &&
             self.mat[0].len() != row.len(){
             return Err(RNMatError::RowDismatch)
@@ -50,13 +61,13 @@ impl RNMat {
         Ok(())
     }
 
-    pub fn push_col(&mut self, col: Vec<RNum>) -> Result<()>{
+    pub fn push_col(&mut self, col: Vec<F>) -> Result<()>{
         let row_cnt = self.mat.len();
         if row_cnt == 0 {
             self.mat.extend(
                 col.into_iter()
                     .map(|ele| vec![ele])
-                    .collect::<Vec<Vec<RNum>>>(),
+                    .collect::<Vec<Vec<F>>>(),
             );
         } else {
             if row_cnt != col.len(){
@@ -80,24 +91,171 @@ impl RNMat {
         Ok(())
     }
 
-    pub fn row_mul_scalar(&mut self, factor: RNum, index: usize) -> Result<()>{
+    pub fn row_mul_scalar(&mut self, factor: F, index: usize) -> Result<()>{
         if self.mat.len() <= index{
             return Err(RNMatError::InvalidIndex);
         }
         self.mat[index]
             .iter_mut()
-            .for_each(|ele| *ele = *ele * factor);
+            .for_each(|ele| *ele = ele.mul(factor));
             Ok(())
     }
 
+    /// Entry at `(row, col)`.
+    pub fn get(&self, row: usize, col: usize) -> Result<F> {
+        if row >= self.row_num() || col >= self.col_num() {
+            return Err(RNMatError::InvalidIndex);
+        }
+        Ok(self.mat[row][col])
+    }
+
+    /// Transpose: `out[j][i] = self[i][j]`.
+    pub fn transpose(&self) -> RNMat<F> {
+        let mut out = RNMat::new();
+        for j in 0..self.col_num() {
+            let col = (0..self.row_num()).map(|i| self.mat[i][j]).collect();
+            out.push_row(col).unwrap();
+        }
+        out
+    }
+
     /// Check before matrix multiplication.
-    fn is_valid_dimension(&self, other: &RNMat) -> bool {
+    fn is_valid_dimension(&self, other: &RNMat<F>) -> bool {
         (self.mat.len() + other.mat.len() == 0) || (self.col_num() == other.mat.len())
     }
+
+    /// Builds the `n x n` identity matrix.
+    pub fn identity(n: usize) -> RNMat<F> {
+        let mut m = RNMat::new();
+        for i in 0..n {
+            let mut row = vec![F::zero(); n];
+            row[i] = F::one();
+            m.push_row(row).unwrap();
+        }
+        m
+    }
+
+    /// Concatenates `self` and `rhs` column-wise, e.g. to build `[A | I]`.
+    fn augmented(&self, rhs: &RNMat<F>) -> Result<RNMat<F>> {
+        if self.row_num() != rhs.row_num() {
+            return Err(RNMatError::RowDismatch);
+        }
+        let mut out = RNMat::new();
+        for i in 0..self.row_num() {
+            let mut row = self.mat[i].clone();
+            row.extend(rhs.mat[i].iter().copied());
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+
+    /// Runs Gauss-Jordan elimination, only pivoting within the first
+    /// `pivot_cols` columns but applying every row operation across all
+    /// columns. Returns the reduced matrix, the rank (number of pivots
+    /// found), the product of the pivot values before normalization, and
+    /// the number of row swaps performed.
+    fn eliminate(&self, pivot_cols: usize) -> (RNMat<F>, usize, F, i32) {
+        let mut m = self.clone();
+        let rows = m.row_num();
+        let mut pivot_row = 0;
+        let mut det_product = F::one();
+        let mut swaps = 0i32;
+        for col in 0..pivot_cols {
+            if pivot_row >= rows {
+                break;
+            }
+            let sel = (pivot_row..rows).find(|&r| !m.mat[r][col].is_zero());
+            let sel = match sel {
+                Some(s) => s,
+                None => continue,
+            };
+            if sel != pivot_row {
+                m.swap_row(sel, pivot_row).unwrap();
+                swaps += 1;
+            }
+            let pivot_val = m.mat[pivot_row][col];
+            det_product = det_product.mul(pivot_val);
+            m.row_mul_scalar(pivot_val.inv(), pivot_row).unwrap();
+            for r in 0..rows {
+                if r != pivot_row && !m.mat[r][col].is_zero() {
+                    let factor = m.mat[r][col];
+                    for c in 0..m.col_num() {
+                        m.mat[r][c] = m.mat[r][c].sub(m.mat[pivot_row][c].mul(factor));
+                    }
+                }
+            }
+            pivot_row += 1;
+        }
+        (m, pivot_row, det_product, swaps)
+    }
+
+    /// Reduced row echelon form.
+    pub fn rref(&self) -> RNMat<F> {
+        self.eliminate(self.col_num()).0
+    }
+
+    /// Rank, i.e. the number of pivot columns found during elimination.
+    pub fn rank(&self) -> usize {
+        self.eliminate(self.col_num()).1
+    }
+
+    /// Determinant, computed from the pivots and swap parity of the
+    /// elimination. Errs if the matrix is not square.
+    pub fn determinant(&self) -> Result<F> {
+        if self.row_num() != self.col_num() {
+            return Err(RNMatError::ColDismatch);
+        }
+        let (_, rank, product, swaps) = self.eliminate(self.col_num());
+        if rank < self.row_num() {
+            return Ok(F::zero());
+        }
+        let sign = if swaps % 2 == 0 { F::one() } else { F::one().neg() };
+        Ok(product.mul(sign))
+    }
+
+    /// Matrix inverse, via Gauss-Jordan elimination on `[A | I]`.
+    pub fn inverse(&self) -> Result<RNMat<F>> {
+        if self.row_num() != self.col_num() {
+            return Err(RNMatError::ColDismatch);
+        }
+        let n = self.row_num();
+        let augmented = self.augmented(&RNMat::identity(n))?;
+        let (reduced, rank, _, _) = augmented.eliminate(n);
+        if rank < n {
+            return Err(RNMatError::Singular);
+        }
+        let mut inv = RNMat::new();
+        for i in 0..n {
+            inv.push_row(reduced.mat[i][n..2 * n].to_vec())?;
+        }
+        Ok(inv)
+    }
+
+    /// Solves `self * x = b` for `x`. Returns `Ok(None)` when the system is
+    /// inconsistent, or when `self` doesn't have full column rank (i.e. the
+    /// solution isn't unique).
+    pub fn solve(&self, b: &RNMat<F>) -> Result<Option<RNMat<F>>> {
+        let n = self.col_num();
+        let augmented = self.augmented(b)?;
+        let (reduced, rank, _, _) = augmented.eliminate(n);
+        for r in rank..self.row_num() {
+            if reduced.mat[r][n..].iter().any(|v| !v.is_zero()) {
+                return Ok(None);
+            }
+        }
+        if rank < n {
+            return Ok(None);
+        }
+        let mut x = RNMat::new();
+        for i in 0..n {
+            x.push_row(reduced.mat[i][n..].to_vec())?;
+        }
+        Ok(Some(x))
+    }
 }
 
-impl From<Vec<Vec<(i32, i32)>>> for RNMat {
-    fn from(vecs: Vec<Vec<(i32, i32)>>) -> RNMat {
+impl From<Vec<Vec<(i32, i32)>>> for RNMat<RNum> {
+    fn from(vecs: Vec<Vec<(i32, i32)>>) -> RNMat<RNum> {
         if vecs.len() == 0 {
             return RNMat { mat: Vec::new() };
         }
@@ -116,7 +274,7 @@ impl From<Vec<Vec<(i32, i32)>>> for RNMat {
     }
 }
 
-impl PartialEq for RNMat {
+impl<F: Field + PartialEq> PartialEq for RNMat<F> {
     fn eq(&self, other: &Self) -> bool {
         // empty.
         let row_cnt = self.mat.len();
@@ -142,6 +300,171 @@ impl PartialEq for RNMat {
     }
 }
 
+impl<F: Field> std::ops::Add for &RNMat<F> {
+    type Output = Result<RNMat<F>>;
+
+    /// Elementwise sum. Errs if the two matrices don't have the same shape.
+    fn add(self, rhs: &RNMat<F>) -> Self::Output {
+        if self.row_num() != rhs.row_num() {
+            return Err(RNMatError::RowDismatch);
+        }
+        if self.col_num() != rhs.col_num() {
+            return Err(RNMatError::ColDismatch);
+        }
+        let mut out = RNMat::new();
+        for i in 0..self.row_num() {
+            let row = (0..self.col_num())
+                .map(|j| self.mat[i][j].add(rhs.mat[i][j]))
+                .collect();
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+}
+
+impl<F: Field> std::ops::Sub for &RNMat<F> {
+    type Output = Result<RNMat<F>>;
+
+    /// Elementwise difference. Errs if the two matrices don't have the same shape.
+    fn sub(self, rhs: &RNMat<F>) -> Self::Output {
+        if self.row_num() != rhs.row_num() {
+            return Err(RNMatError::RowDismatch);
+        }
+        if self.col_num() != rhs.col_num() {
+            return Err(RNMatError::ColDismatch);
+        }
+        let mut out = RNMat::new();
+        for i in 0..self.row_num() {
+            let row = (0..self.col_num())
+                .map(|j| self.mat[i][j].sub(rhs.mat[i][j]))
+                .collect();
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+}
+
+impl<F: Field> std::ops::Neg for &RNMat<F> {
+    type Output = RNMat<F>;
+
+    fn neg(self) -> Self::Output {
+        let mut out = RNMat::new();
+        for row in &self.mat {
+            out.push_row(row.iter().map(|ele| ele.neg()).collect()).unwrap();
+        }
+        out
+    }
+}
+
+impl<F: Field> std::ops::Mul for &RNMat<F> {
+    type Output = Result<RNMat<F>>;
+
+    /// `C[i][j] = Σ_k A[i][k] * B[k][j]`, accumulated with plain field
+    /// addition. For `RNum`, see `mul_reduced` for a variant that reduces
+    /// via `gcd` once per dot-product instead of after every term.
+    fn mul(self, rhs: &RNMat<F>) -> Self::Output {
+        if !self.is_valid_dimension(rhs) {
+            return Err(RNMatError::ColDismatch);
+        }
+        if self.row_num() == 0 || rhs.col_num() == 0 {
+            return Ok(RNMat::new());
+        }
+        let mut out = RNMat::new();
+        for i in 0..self.row_num() {
+            let mut row = Vec::with_capacity(rhs.col_num());
+            for j in 0..rhs.col_num() {
+                let mut sum = F::zero();
+                for k in 0..self.col_num() {
+                    sum = sum.add(self.mat[i][k].mul(rhs.mat[k][j]));
+                }
+                row.push(sum);
+            }
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+}
+
+impl RNMat<RNum> {
+    /// Same product as `&RNMat * &RNMat`, but accumulates each
+    /// dot-product over a common denominator (the product of every
+    /// term's denominator) and reduces once at the end, instead of
+    /// reducing via `gcd` after every addition. Intermediate arithmetic is
+    /// `i128` with `checked_*`, erring with `RNMatError::Overflow` instead
+    /// of wrapping, since `common_deno` grows with the number of columns.
+    pub fn mul_reduced(&self, rhs: &RNMat<RNum>) -> Result<RNMat<RNum>> {
+        if !self.is_valid_dimension(rhs) {
+            return Err(RNMatError::ColDismatch);
+        }
+        if self.row_num() == 0 || rhs.col_num() == 0 {
+            return Ok(RNMat::new());
+        }
+        let mut out = RNMat::new();
+        for i in 0..self.row_num() {
+            let mut row = Vec::with_capacity(rhs.col_num());
+            for j in 0..rhs.col_num() {
+                let mut common_deno: i128 = 1;
+                for k in 0..self.col_num() {
+                    let term_deno = self.mat[i][k].denom() as i128 * rhs.mat[k][j].denom() as i128;
+                    common_deno = common_deno.checked_mul(term_deno).ok_or(RNMatError::Overflow)?;
+                }
+                let mut nume_sum: i128 = 0;
+                for k in 0..self.col_num() {
+                    let a = self.mat[i][k];
+                    let b = rhs.mat[k][j];
+                    let term_deno = a.denom() as i128 * b.denom() as i128;
+                    let term = (a.numer() as i128)
+                        .checked_mul(b.numer() as i128)
+                        .and_then(|v| v.checked_mul(common_deno / term_deno))
+                        .ok_or(RNMatError::Overflow)?;
+                    nume_sum = nume_sum.checked_add(term).ok_or(RNMatError::Overflow)?;
+                }
+                row.push(RNum::from_signed_i128(nume_sum, common_deno).ok_or(RNMatError::Overflow)?);
+            }
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+
+    /// Parses a MATLAB-style literal such as `"[1/2 3; 4 -5/6]"`: rows
+    /// separated by `;`, entries separated by whitespace, each entry an
+    /// `int` or `int/int`.
+    pub fn parse(s: &str) -> Result<RNMat<RNum>> {
+        let s = s.trim();
+        let inner = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(RNMatError::ParseError)?;
+        let mut out = RNMat::new();
+        for row_str in inner.split(';') {
+            let row = row_str
+                .split_whitespace()
+                .map(parse_rnum_entry)
+                .collect::<Result<Vec<RNum>>>()?;
+            if row.is_empty() {
+                continue;
+            }
+            out.push_row(row)?;
+        }
+        Ok(out)
+    }
+}
+
+/// Parses a single entry of a matrix literal: `int` or `int/int`.
+fn parse_rnum_entry(s: &str) -> Result<RNum> {
+    match s.split_once('/') {
+        Some((n, d)) => {
+            let n: i32 = n.parse().map_err(|_| RNMatError::ParseError)?;
+            let d: i32 = d.parse().map_err(|_| RNMatError::ParseError)?;
+            RNum::safe_make(n, d).ok_or(RNMatError::ParseError)
+        }
+        None => {
+            let n: i32 = s.parse().map_err(|_| RNMatError::ParseError)?;
+            Ok(RNum::new(n, 1))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_rnmat {
     use super::*;
@@ -215,7 +538,10 @@ mod test_rnmat {
 
     #[test]
     fn test_is_valid_dimension() {
-        assert_eq!(true, RNMat::new().is_valid_dimension(&RNMat::new()));
+        assert_eq!(
+            true,
+            RNMat::<RNum>::new().is_valid_dimension(&RNMat::new())
+        );
         assert_eq!(
             false,
             RNMat::new().is_valid_dimension(&RNMat::from(vec![vec![(1, 2)]]))
@@ -272,4 +598,215 @@ mod test_rnmat {
         let mut mat = RNMat::new();
         mat.row_mul_scalar(RNum::new(1, 2), 0).unwrap();
     }
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(
+            RNMat::identity(2),
+            RNMat::from(vec![vec![(1, 1), (0, 1)], vec![(0, 1), (1, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_rref() {
+        let mat = RNMat::from(vec![vec![(2, 1), (4, 1)], vec![(1, 1), (1, 1)]]);
+        assert_eq!(
+            mat.rref(),
+            RNMat::from(vec![vec![(1, 1), (0, 1)], vec![(0, 1), (1, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(
+            2,
+            RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]).rank()
+        );
+        assert_eq!(
+            1,
+            RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(2, 1), (4, 1)]]).rank()
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(
+            RNum::new(-2, 1),
+            RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]])
+                .determinant()
+                .unwrap()
+        );
+        assert_eq!(
+            RNum::zero(),
+            RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(2, 1), (4, 1)]])
+                .determinant()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_determinant_not_square() {
+        assert!(matches!(
+            RNMat::from(vec![vec![(1, 1), (2, 1)]]).determinant(),
+            Err(RNMatError::ColDismatch)
+        ));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mat = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]);
+        let inv = mat.inverse().unwrap();
+        assert_eq!(
+            inv,
+            RNMat::from(vec![vec![(-2, 1), (1, 1)], vec![(3, 2), (-1, 2)]])
+        );
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let mat = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(2, 1), (4, 1)]]);
+        assert!(matches!(mat.inverse(), Err(RNMatError::Singular)));
+    }
+
+    #[test]
+    fn test_inverse_not_square() {
+        assert!(matches!(
+            RNMat::from(vec![vec![(1, 1), (2, 1)]]).inverse(),
+            Err(RNMatError::ColDismatch)
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]);
+        let b = RNMat::from(vec![vec![(5, 1)], vec![(6, 1)]]);
+        let x = a.solve(&b).unwrap().unwrap();
+        assert_eq!(x, RNMat::from(vec![vec![(-4, 1)], vec![(9, 2)]]));
+    }
+
+    #[test]
+    fn test_solve_inconsistent_system() {
+        // Row 2 is 2x row 1, but the RHS isn't scaled the same way, so no
+        // x can satisfy both equations.
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(2, 1), (4, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 1)], vec![(3, 1)]]);
+        assert_eq!(None, a.solve(&b).unwrap());
+    }
+
+    #[test]
+    fn test_solve_rank_deficient_but_consistent() {
+        // Row 2 is 2x row 1, and the RHS is scaled the same way, so the
+        // augmented tail beyond the pivot rank is all zero: the system has
+        // infinitely many solutions rather than being inconsistent.
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(2, 1), (4, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 1)], vec![(2, 1)]]);
+        assert_eq!(None, a.solve(&b).unwrap());
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 2), (0, 1)], vec![(0, 1), (1, 2)]]);
+        assert_eq!(
+            (&a * &b).unwrap(),
+            RNMat::from(vec![vec![(1, 2), (1, 1)], vec![(3, 2), (2, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_mul_dimension_mismatch() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 1), (2, 1)]]);
+        assert!(matches!(&a * &b, Err(RNMatError::ColDismatch)));
+    }
+
+    #[test]
+    fn test_mul_reduced_matches_mul() {
+        let a = RNMat::from(vec![vec![(1, 2), (2, 3)], vec![(3, 4), (1, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 1), (1, 2)], vec![(2, 1), (1, 3)]]);
+        assert_eq!((&a * &b).unwrap(), a.mul_reduced(&b).unwrap());
+    }
+
+    #[test]
+    fn test_mul_reduced_wide_dot_product_does_not_overflow() {
+        // A 1x4 times 4x1 dot product: common_deno is the *product* of
+        // every term's denominator pair (1000*2000*3000*4000 = 2.4e13),
+        // far beyond the i32 range of a single RNum denominator.
+        let a = RNMat::from(vec![vec![(1, 1000), (1, 2000), (1, 3000), (1, 4000)]]);
+        let b = RNMat::from(vec![vec![(1, 1)], vec![(1, 1)], vec![(1, 1)], vec![(1, 1)]]);
+        assert_eq!((&a * &b).unwrap(), a.mul_reduced(&b).unwrap());
+    }
+
+    #[test]
+    fn test_mul_reduced_overflow_errs() {
+        // `common_deno` is the product of every column's denominator pair;
+        // three columns of denominator ~2e9 each overflow even `i128`.
+        let d = 2_000_000_000;
+        let a = RNMat::from(vec![vec![(1, d), (1, d), (1, d)]]);
+        let b = RNMat::from(vec![vec![(1, d)], vec![(1, d)], vec![(1, d)]]);
+        assert!(matches!(a.mul_reduced(&b), Err(RNMatError::Overflow)));
+    }
+
+    #[test]
+    fn test_mod_int_field() {
+        use super::super::modint::ModInt;
+        let mut mat: RNMat<ModInt<7>> = RNMat::new();
+        mat.push_row(vec![ModInt::new(1), ModInt::new(2)]).unwrap();
+        mat.push_row(vec![ModInt::new(3), ModInt::new(4)]).unwrap();
+        // det = 1*4 - 2*3 = -2 = 5 (mod 7)
+        assert_eq!(ModInt::new(5), mat.determinant().unwrap());
+    }
+
+    #[test]
+    fn test_get() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]);
+        assert_eq!(RNum::new(3, 1), a.get(1, 0).unwrap());
+        assert!(matches!(a.get(2, 0), Err(RNMatError::InvalidIndex)));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1), (3, 1)], vec![(4, 1), (5, 1), (6, 1)]]);
+        assert_eq!(
+            a.transpose(),
+            RNMat::from(vec![vec![(1, 1), (4, 1)], vec![(2, 1), (5, 1)], vec![(3, 1), (6, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)], vec![(3, 1), (4, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 2), (1, 2)], vec![(1, 2), (1, 2)]]);
+        assert_eq!(
+            (&a + &b).unwrap(),
+            RNMat::from(vec![vec![(3, 2), (5, 2)], vec![(7, 2), (9, 2)]])
+        );
+        assert_eq!(
+            (&a - &b).unwrap(),
+            RNMat::from(vec![vec![(1, 2), (3, 2)], vec![(5, 2), (7, 2)]])
+        );
+        assert_eq!(-&a, RNMat::from(vec![vec![(-1, 1), (-2, 1)], vec![(-3, 1), (-4, 1)]]));
+    }
+
+    #[test]
+    fn test_add_dimension_mismatch() {
+        let a = RNMat::from(vec![vec![(1, 1), (2, 1)]]);
+        let b = RNMat::from(vec![vec![(1, 1)]]);
+        assert!(matches!(&a + &b, Err(RNMatError::ColDismatch)));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            RNMat::parse("[1/2 3; 4 -5/6]").unwrap(),
+            RNMat::from(vec![vec![(1, 2), (3, 1)], vec![(4, 1), (-5, 6)]])
+        );
+        assert_eq!(RNMat::parse("[]").unwrap(), RNMat::new());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(RNMat::parse("1/2 3; 4 5"), Err(RNMatError::ParseError)));
+        assert!(matches!(RNMat::parse("[1/2 x; 4 5]"), Err(RNMatError::ParseError)));
+    }
 }
@@ -0,0 +1,122 @@
+use super::field::Field;
+
+/// An element of `GF(P)`, for a prime `P`. Values are stored already
+/// reduced mod `P`.
+///
+/// # Examples
+///
+/// ```
+/// use rnmat::modint::ModInt;
+/// let a: ModInt<7> = ModInt::new(5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt<const P: u32> {
+    v: u32,
+}
+
+impl<const P: u32> ModInt<P> {
+    pub fn new(v: u32) -> ModInt<P> {
+        ModInt { v: v % P }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.v
+    }
+
+    /// `self^exp mod P`, via square-and-multiply.
+    fn pow(self, mut exp: u32) -> ModInt<P> {
+        let mut result = ModInt::<P>::new(1);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(base);
+            }
+            base = base.mul(base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl<const P: u32> Field for ModInt<P> {
+    fn zero() -> Self {
+        ModInt::new(0)
+    }
+
+    fn one() -> Self {
+        ModInt::new(1)
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        ModInt::new(((self.v as u64 + rhs.v as u64) % P as u64) as u32)
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        ModInt::new(((self.v as u64 + P as u64 - rhs.v as u64) % P as u64) as u32)
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        ModInt::new(((self.v as u64 * rhs.v as u64) % P as u64) as u32)
+    }
+
+    /// `a^(P-2) mod P`, by Fermat's little theorem. Panics on zero, which
+    /// has no inverse in `GF(P)`.
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "Error, cannot invert zero in GF(P).");
+        self.pow(P - 2)
+    }
+
+    fn neg(self) -> Self {
+        if self.v == 0 {
+            self
+        } else {
+            ModInt::new(P - self.v)
+        }
+    }
+
+    fn is_zero(self) -> bool {
+        self.v == 0
+    }
+}
+
+#[cfg(test)]
+mod test_modint {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces() {
+        assert_eq!(2, ModInt::<7>::new(9).value());
+        assert_eq!(0, ModInt::<7>::new(7).value());
+    }
+
+    #[test]
+    fn test_add_sub() {
+        assert_eq!(ModInt::<7>::new(2), ModInt::<7>::new(5).add(ModInt::<7>::new(4)));
+        assert_eq!(ModInt::<7>::new(5), ModInt::<7>::new(2).sub(ModInt::<7>::new(4)));
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(ModInt::<7>::new(5), ModInt::<7>::new(3).mul(ModInt::<7>::new(4)));
+    }
+
+    #[test]
+    fn test_inv() {
+        for a in 1..7u32 {
+            let a = ModInt::<7>::new(a);
+            assert_eq!(ModInt::<7>::new(1), a.mul(a.inv()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_zero() {
+        let _ = ModInt::<7>::new(0).inv();
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(ModInt::<7>::new(0), ModInt::<7>::new(0).neg());
+        assert_eq!(ModInt::<7>::new(4), ModInt::<7>::new(3).neg());
+    }
+}
@@ -1,4 +1,17 @@
+use super::field::Field;
 use super::utils::*;
+
+/// Packs `v` into a `u32` only if it also fits `i32`, i.e. the range
+/// `RNum::numer`/`RNum::denom` can actually expose as signed values.
+fn fits_i32(v: u128) -> Option<u32> {
+    let v32 = u32::try_from(v).ok()?;
+    if v32 > i32::MAX as u32 {
+        None
+    } else {
+        Some(v32)
+    }
+}
+
 /// RNum represents a rational number.
 ///
 /// # Examples
@@ -57,30 +70,111 @@ impl RNum {
         return self.nume == 0;
     }
 
+    /// Signed numerator.
+    pub fn numer(&self) -> i32 {
+        let n = self.nume as i32;
+        if self.neg_flag {
+            -n
+        } else {
+            n
+        }
+    }
+
+    /// Denominator, always positive.
+    pub fn denom(&self) -> i32 {
+        self.deno as i32
+    }
+
     pub fn zero() -> RNum {
         return RNum::new(0, 1);
     }
+}
+// ===============================================================
+// Checked arithmetic: reduce before multiplying, with i128
+// intermediates, so that operands far smaller than the naive `b*d`
+// denominator are what actually risk overflow.
+// ===============================================================
 
-    /// Use gcd() to reduce denominator and numerator.
-    fn reduce(&mut self) {
-        assert!(self.deno != 0, "Error, denominator is zero.");
-        if self.nume == 0 {
-            self.neg_flag = false;
-            self.deno = 1;
+impl RNum {
+    fn signed_nume_i128(&self) -> i128 {
+        let n = self.nume as i128;
+        if self.neg_flag {
+            -n
         } else {
-            let gcd_num = gcd(self.deno, self.nume);
-            if gcd_num > 1 {
-                self.deno /= gcd_num;
-                self.nume /= gcd_num;
-            }
+            n
         }
     }
+
+    /// Reduces a signed numerator over a strictly positive denominator and
+    /// packs the result back into `RNum`'s `u32` fields. `None` if the
+    /// reduced values still don't fit `i32`, the range `numer()`/`denom()`
+    /// actually expose (not the full `u32` range the fields could hold).
+    ///
+    /// `pub(crate)` so other `i128`-intermediate arithmetic in the crate
+    /// (e.g. `RNMat::mul_reduced`) can reduce-and-pack the same way instead
+    /// of duplicating this logic.
+    pub(crate) fn from_signed_i128(nume: i128, deno: i128) -> Option<RNum> {
+        debug_assert!(deno > 0);
+        if nume == 0 {
+            return Some(RNum::zero());
+        }
+        let nume_abs = nume.unsigned_abs();
+        let deno_abs = deno as u128;
+        let g = gcd128(nume_abs, deno_abs);
+        Some(RNum {
+            neg_flag: nume < 0,
+            nume: fits_i32(nume_abs / g)?,
+            deno: fits_i32(deno_abs / g)?,
+        })
+    }
+
+    /// `self + rhs`, reducing `self.deno`/`rhs.deno` by their `gcd` before
+    /// forming the combined denominator. `None` on overflow, instead of
+    /// silently wrapping.
+    pub fn checked_add(self, rhs: Self) -> Option<RNum> {
+        let a = self.signed_nume_i128();
+        let b = self.deno as i128;
+        let c = rhs.signed_nume_i128();
+        let d = rhs.deno as i128;
+        let g = gcd128(b as u128, d as u128) as i128;
+        let b2 = b / g;
+        let d2 = d / g;
+        let nume = a.checked_mul(d2)?.checked_add(c.checked_mul(b2)?)?;
+        let deno = b.checked_mul(d2)?;
+        RNum::from_signed_i128(nume, deno)
+    }
+
+    /// `self - rhs`. See `checked_add`.
+    pub fn checked_sub(self, rhs: Self) -> Option<RNum> {
+        self.checked_add(-rhs)
+    }
+
+    /// `self * rhs`, cross-cancelling `gcd(self.nume, rhs.deno)` and
+    /// `gcd(rhs.nume, self.deno)` before forming the product. `None` on
+    /// overflow, instead of silently wrapping.
+    pub fn checked_mul(self, rhs: Self) -> Option<RNum> {
+        let a = self.nume as i128;
+        let b = self.deno as i128;
+        let c = rhs.nume as i128;
+        let d = rhs.deno as i128;
+        let g1 = gcd128(a as u128, d as u128) as i128;
+        let g2 = gcd128(c as u128, b as u128) as i128;
+        let nume = (a / g1).checked_mul(c / g2)?;
+        let deno = (b / g2).checked_mul(d / g1)?;
+        let signed_nume = if self.neg_flag ^ rhs.neg_flag {
+            -nume
+        } else {
+            nume
+        };
+        RNum::from_signed_i128(signed_nume, deno)
+    }
+
+    /// `self / rhs`. Panics if `rhs` is zero, like `RNum::new` panics on a
+    /// zero denominator. `None` on overflow, instead of silently wrapping.
+    pub fn checked_div(self, rhs: Self) -> Option<RNum> {
+        self.checked_mul(num_traits::Inv::inv(rhs))
+    }
 }
-// ===============================================================
-// TODO
-// impl PartialEq, Eq, Ord, ...
-// impl Add, Mul, Minus, Div
-// ===============================================================
 
 impl PartialEq for RNum {
     fn eq(&self, other: &Self) -> bool {
@@ -89,82 +183,76 @@ impl PartialEq for RNum {
     }
 }
 
+impl Eq for RNum {}
+
+impl PartialOrd for RNum {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RNum {
+    /// `self.numer() * other.denom()` vs `other.numer() * self.denom()`,
+    /// widened to `i128` so the cross-multiplication can't overflow.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let lhs = self.numer() as i128 * other.denom() as i128;
+        let rhs = other.numer() as i128 * self.denom() as i128;
+        lhs.cmp(&rhs)
+    }
+}
+
+impl std::fmt::Display for RNum {
+    /// Renders as `"-3/4"`, collapsing integers (`deno == 1`) to `"-3"` and
+    /// zero to `"0"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else if self.deno == 1 {
+            write!(f, "{}", self.numer())
+        } else {
+            write!(f, "{}/{}", self.numer(), self.deno)
+        }
+    }
+}
+
 impl std::ops::Add for RNum {
     type Output = RNum;
     fn add(self, rhs: Self) -> Self::Output {
-        let deno = self.deno * rhs.deno;
-        let mut nume = self.nume * rhs.deno;
-        if rhs.neg_flag {
-            nume -= self.deno * rhs.nume;
-        } else {
-            nume += self.deno * rhs.nume;
-        }
-        let flag = self.neg_flag ^ rhs.neg_flag;
-        // TODO: consider overflow.
-        let mut res = RNum {
-            neg_flag: flag,
-            nume: nume,
-            deno: deno,
-        };
-        res.reduce();
-        res
+        self.checked_add(rhs).expect("Error, RNum overflow in addition.")
     }
 }
 
 impl std::ops::Sub for RNum {
     type Output = Self;
     fn sub(self, rhs: RNum) -> Self::Output {
-        let deno = self.deno * rhs.deno;
-        let mut nume = self.nume * rhs.deno;
-        if rhs.neg_flag {
-            nume += self.deno * rhs.nume;
-        } else {
-            nume -= self.deno * rhs.nume;
-        }
-        let flag = !(self.neg_flag ^ rhs.neg_flag);
-        // TODO: consider overflow.
-        let mut res = RNum {
-            neg_flag: flag,
-            nume: nume,
-            deno: deno,
-        };
-        res.reduce();
-        res
+        self.checked_sub(rhs).expect("Error, RNum overflow in subtraction.")
     }
 }
 
 impl std::ops::Mul for RNum {
     type Output = Self;
     fn mul(self, rhs: RNum) -> Self::Output {
-        let deno = self.deno * rhs.deno;
-        let nume = self.nume * rhs.nume;
-        let flag = self.neg_flag ^ rhs.neg_flag;
-        // TODO: consider overflow.
-        let mut res = RNum {
-            neg_flag: flag,
-            nume: nume,
-            deno: deno,
-        };
-        res.reduce();
-        res
+        self.checked_mul(rhs).expect("Error, RNum overflow in multiplication.")
     }
 }
 
 impl std::ops::Div for RNum {
     type Output = Self;
     fn div(self, rhs: RNum) -> Self::Output {
-        let deno = self.deno * rhs.nume;
-        let nume = self.nume * rhs.deno;
-        // TODO: consider overflow.
-        let flag = self.neg_flag ^ rhs.neg_flag;
-        // TODO: consider overflow.
-        let mut res = RNum {
-            neg_flag: flag,
-            nume: nume,
-            deno: deno,
-        };
-        res.reduce();
-        res
+        self.checked_div(rhs).expect("Error, RNum overflow in division.")
+    }
+}
+
+impl std::ops::Rem for RNum {
+    type Output = Self;
+    /// Truncating remainder: `self - rhs * trunc(self / rhs)`. Needed
+    /// because `num_traits::Num` requires the full `NumOps` set (`Rem`
+    /// included), even though exact rational division never leaves a
+    /// "remainder" in the everyday sense.
+    fn rem(self, rhs: Self) -> Self::Output {
+        let q = self / rhs;
+        let trunc = q.numer() / q.denom();
+        self - rhs * RNum::new(trunc, 1)
     }
 }
 
@@ -183,6 +271,211 @@ impl std::ops::Neg for RNum {
     }
 }
 
+// ===============================================================
+// num-traits integration, so RNum can drop into generic algorithms
+// bounded by num_traits::Num.
+// ===============================================================
+
+/// Error returned when a string cannot be parsed as a `RNum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseRNumError;
+
+impl std::fmt::Display for ParseRNumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid rational number literal")
+    }
+}
+
+impl std::error::Error for ParseRNumError {}
+
+impl num_traits::Zero for RNum {
+    fn zero() -> Self {
+        RNum::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        RNum::is_zero(self)
+    }
+}
+
+impl num_traits::One for RNum {
+    fn one() -> Self {
+        RNum::new(1, 1)
+    }
+}
+
+impl num_traits::Num for RNum {
+    type FromStrRadixErr = ParseRNumError;
+
+    /// Parses `"3"` or `"3/4"`-style strings. Only `radix == 10` is supported,
+    /// since `RNum` has no notion of a non-decimal rational literal.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseRNumError);
+        }
+        let str = str.trim();
+        match str.split_once('/') {
+            Some((n, d)) => {
+                let n: i32 = n.trim().parse().map_err(|_| ParseRNumError)?;
+                let d: i32 = d.trim().parse().map_err(|_| ParseRNumError)?;
+                RNum::safe_make(n, d).ok_or(ParseRNumError)
+            }
+            None => {
+                let n: i32 = str.parse().map_err(|_| ParseRNumError)?;
+                Ok(RNum::new(n, 1))
+            }
+        }
+    }
+}
+
+impl num_traits::Signed for RNum {
+    fn abs(&self) -> Self {
+        RNum {
+            neg_flag: false,
+            nume: self.nume,
+            deno: self.deno,
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        let diff = *self - *other;
+        if diff.is_negative() {
+            RNum::zero()
+        } else {
+            diff
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if self.is_zero() {
+            RNum::zero()
+        } else if self.is_negative() {
+            RNum::new(-1, 1)
+        } else {
+            RNum::new(1, 1)
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        RNum::is_positive(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        RNum::is_negative(self)
+    }
+}
+
+impl num_traits::Inv for RNum {
+    type Output = Self;
+
+    /// Returns the reciprocal. Panics on zero, mirroring `RNum::new`'s
+    /// handling of a zero denominator.
+    fn inv(self) -> Self::Output {
+        assert!(!self.is_zero(), "Error, cannot invert zero.");
+        RNum {
+            neg_flag: self.neg_flag,
+            nume: self.deno,
+            deno: self.nume,
+        }
+    }
+}
+
+impl num_traits::FromPrimitive for RNum {
+    fn from_i64(n: i64) -> Option<Self> {
+        i32::try_from(n).ok().map(|n| RNum::new(n, 1))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        u32::try_from(n).ok().map(|n| RNum::new(n as i32, 1))
+    }
+
+    /// Approximates `n` as a rational with a bounded denominator, by scanning
+    /// denominators `1..=1_000_000` for the closest numerator.
+    fn from_f64(n: f64) -> Option<Self> {
+        if !n.is_finite() {
+            return None;
+        }
+        if n == 0.0 {
+            return Some(RNum::zero());
+        }
+        const MAX_DENO: i64 = 1_000_000;
+        let mut best: Option<(i64, i64, f64)> = None;
+        let mut d = 1i64;
+        while d <= MAX_DENO {
+            let num = (n * d as f64).round();
+            if num.abs() >= i32::MAX as f64 {
+                break;
+            }
+            let approx = num / d as f64;
+            let err = (approx - n).abs();
+            if best.is_none_or(|(_, _, best_err)| err < best_err) {
+                best = Some((num as i64, d, err));
+                if err < 1e-12 {
+                    break;
+                }
+            }
+            d += 1;
+        }
+        best.map(|(num, d, _)| RNum::new(num as i32, d as i32))
+    }
+}
+
+impl Field for RNum {
+    fn zero() -> Self {
+        RNum::zero()
+    }
+
+    fn one() -> Self {
+        RNum::new(1, 1)
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn inv(self) -> Self {
+        num_traits::Inv::inv(self)
+    }
+
+    fn neg(self) -> Self {
+        -self
+    }
+
+    fn is_zero(self) -> bool {
+        RNum::is_zero(&self)
+    }
+}
+
+impl num_traits::ToPrimitive for RNum {
+    fn to_i64(&self) -> Option<i64> {
+        if self.deno != 1 {
+            return None;
+        }
+        let n = self.nume as i64;
+        Some(if self.neg_flag { -n } else { n })
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        if self.deno != 1 || self.neg_flag {
+            return None;
+        }
+        Some(self.nume as u64)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let v = self.nume as f64 / self.deno as f64;
+        Some(if self.neg_flag { -v } else { v })
+    }
+}
+
 #[cfg(test)]
 mod test_rnum {
     use super::*;
@@ -194,6 +487,32 @@ mod test_rnum {
         assert_eq!(RNum::new(4, 2), RNum::new(2, 1));
     }
 
+    #[test]
+    fn test_ord() {
+        assert!(RNum::new(1, 2) < RNum::new(3, 4));
+        assert!(RNum::new(-1, 2) < RNum::new(0, 1));
+        assert!(RNum::new(-3, 4) < RNum::new(-1, 2));
+        assert_eq!(RNum::new(1, 2), RNum::new(2, 4));
+        assert_eq!(RNum::new(1, 2).cmp(&RNum::new(2, 4)), std::cmp::Ordering::Equal);
+
+        let mut v = vec![RNum::new(1, 2), RNum::new(-3, 4), RNum::new(0, 1), RNum::new(5, 1)];
+        v.sort();
+        assert_eq!(
+            v,
+            vec![RNum::new(-3, 4), RNum::new(0, 1), RNum::new(1, 2), RNum::new(5, 1)]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("0", RNum::new(0, 5).to_string());
+        assert_eq!("3", RNum::new(3, 1).to_string());
+        assert_eq!("-3", RNum::new(-3, 1).to_string());
+        assert_eq!("3/4", RNum::new(3, 4).to_string());
+        assert_eq!("-3/4", RNum::new(-3, 4).to_string());
+        assert_eq!("3/4", RNum::new(6, 8).to_string());
+    }
+
     #[test]
     fn test_negative() {
         assert_eq!(true, RNum::new(1, -2).is_negative());
@@ -260,6 +579,21 @@ mod test_rnum {
         let _ = RNum::new(1, 2) / RNum::new(0, 1);
     }
 
+    #[test]
+    fn test_rem() {
+        assert_eq!(RNum::new(1, 2), RNum::new(7, 2) % RNum::new(3, 1));
+        assert_eq!(RNum::zero(), RNum::new(4, 1) % RNum::new(2, 1));
+    }
+
+    #[test]
+    fn test_numer_denom() {
+        assert_eq!(3, RNum::new(3, 4).numer());
+        assert_eq!(4, RNum::new(3, 4).denom());
+        assert_eq!(-3, RNum::new(-3, 4).numer());
+        assert_eq!(0, RNum::zero().numer());
+        assert_eq!(1, RNum::zero().denom());
+    }
+
     #[test]
     fn test_neg() {
         assert_eq!(RNum::new(0, 1), -RNum::new(0, 1));
@@ -275,4 +609,111 @@ mod test_rnum {
         assert_eq!(p2, 0xfffffffe);
         assert_eq!(p2 << 1, 0xfffffffc);
     }
+
+    #[test]
+    fn test_add_avoids_naive_overflow() {
+        // 100_000 * 50_000 overflows u32::MAX, but the reduced denominator
+        // (100_000) fits comfortably.
+        assert_eq!(
+            RNum::new(3, 100_000),
+            RNum::new(1, 100_000) + RNum::new(1, 50_000)
+        );
+    }
+
+    #[test]
+    fn test_checked_add_overflow_returns_none() {
+        // Two large coprime denominators: the true reduced result doesn't
+        // fit back into `u32`.
+        let a = RNum::new(1, 2_147_483_629);
+        let b = RNum::new(1, 2_147_483_647);
+        assert_eq!(None, a.checked_add(b));
+    }
+
+    #[test]
+    fn test_checked_mul_overflow_returns_none() {
+        let a = RNum::new(2_147_483_629, 1);
+        let b = RNum::new(2_147_483_647, 1);
+        assert_eq!(None, a.checked_mul(b));
+    }
+
+    #[test]
+    fn test_checked_add_rejects_result_outside_i32_range() {
+        // The u32 sum (2_200_000_000) fits u32 but not i32, so numer()
+        // couldn't expose it correctly; checked_add must reject it rather
+        // than hand back a RNum whose numer()/denom() silently wrap.
+        let a = RNum::new(2_000_000_000, 1);
+        let b = RNum::new(200_000_000, 1);
+        assert_eq!(None, a.checked_add(b));
+    }
+
+    #[test]
+    fn test_checked_sub_and_div_match_operators() {
+        assert_eq!(
+            Some(RNum::new(1, 2)),
+            RNum::new(1, 4).checked_sub(RNum::new(-1, 4))
+        );
+        assert_eq!(
+            Some(RNum::new(1, 1)),
+            RNum::new(1, 2).checked_div(RNum::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_num_traits_zero_one() {
+        use num_traits::Zero as NumTraitsZero;
+        assert_eq!(RNum::new(0, 1), RNum::zero());
+        assert!(NumTraitsZero::is_zero(&RNum::zero()));
+        assert_eq!(RNum::new(1, 1), <RNum as num_traits::One>::one());
+    }
+
+    #[test]
+    fn test_num_traits_from_str_radix() {
+        use num_traits::Num;
+        assert_eq!(RNum::new(3, 4), RNum::from_str_radix("3/4", 10).unwrap());
+        assert_eq!(RNum::new(-3, 4), RNum::from_str_radix("-3/4", 10).unwrap());
+        assert_eq!(RNum::new(5, 1), RNum::from_str_radix("5", 10).unwrap());
+        assert!(RNum::from_str_radix("3/4", 16).is_err());
+        assert!(RNum::from_str_radix("nope", 10).is_err());
+    }
+
+    #[test]
+    fn test_num_traits_signed() {
+        use num_traits::Signed;
+        assert_eq!(RNum::new(1, 2), RNum::new(-1, 2).abs());
+        assert_eq!(RNum::new(1, 1), RNum::new(1, 2).signum());
+        assert_eq!(RNum::new(-1, 1), RNum::new(-1, 2).signum());
+        assert_eq!(RNum::zero(), RNum::zero().signum());
+    }
+
+    #[test]
+    fn test_num_traits_inv() {
+        use num_traits::Inv;
+        assert_eq!(RNum::new(4, 3), Inv::inv(RNum::new(3, 4)));
+        assert_eq!(RNum::new(-4, 3), Inv::inv(RNum::new(-3, 4)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_num_traits_inv_zero() {
+        use num_traits::Inv;
+        let _ = Inv::inv(RNum::zero());
+    }
+
+    #[test]
+    fn test_field_impl() {
+        assert_eq!(RNum::new(3, 4), RNum::new(1, 4).add(RNum::new(1, 2)));
+        assert_eq!(RNum::new(4, 3), RNum::new(3, 4).inv());
+        assert!(RNum::zero().is_zero());
+    }
+
+    #[test]
+    fn test_num_traits_from_to_primitive() {
+        use num_traits::{FromPrimitive, ToPrimitive};
+        assert_eq!(RNum::new(5, 1), RNum::from_i64(5).unwrap());
+        assert_eq!(RNum::new(5, 1), RNum::from_u64(5).unwrap());
+        assert_eq!(RNum::new(1, 2), RNum::from_f64(0.5).unwrap());
+        assert_eq!(Some(5), RNum::new(5, 1).to_i64());
+        assert_eq!(None, RNum::new(1, 2).to_i64());
+        assert_eq!(Some(0.5), RNum::new(1, 2).to_f64());
+    }
 }
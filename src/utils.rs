@@ -26,6 +26,28 @@ pub fn get_reduced_pair(a:u32, b:u32) -> (u32, u32){
     (a / gcd_num, b / gcd_num)
 }
 
+/// Same algorithm as `gcd`, widened to `u128` for intermediate arithmetic
+/// that doesn't fit in `u32`.
+#[inline]
+pub fn gcd128(mut a: u128, mut b: u128) -> u128 {
+    if a < b {
+        std::mem::swap(&mut a, &mut b);
+    }
+    if b == 1 {
+        1
+    } else if a == b || b == 0 {
+        a
+    } else {
+        let mut r = a % b;
+        while r > 0 {
+            a = b;
+            b = r;
+            r = a % b;
+        }
+        b
+    }
+}
+
 
 #[cfg(test)]
 mod utils_test{
@@ -50,5 +72,15 @@ mod utils_test{
         assert_eq!(res, gcd(16, -4));
         */
     }
+
+    #[test]
+    fn test_gcd128() {
+        assert_eq!(1, gcd128(1, 1));
+        assert_eq!(2, gcd128(2, 0));
+        assert_eq!(2, gcd128(10, 2));
+        assert_eq!(gcd128(9, 3), gcd128(3, 9));
+        // wider than u32::MAX
+        assert_eq!(1_000_000_007, gcd128(1_000_000_007 * 3, 1_000_000_007 * 5));
+    }
 }
 